@@ -29,6 +29,17 @@ pub(crate) const LOOKUP: [Option<(u8, u8)>; 128] = {
 pub(crate) const SPAN: f64 = 36.0;
 pub(crate) const POWER: u32 = 1 << 20;
 
+/// Mean Earth radius, in meters, used by the haversine distance calculations.
+pub(crate) const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+/// WGS84 ellipsoid semi-major axis, in meters, used by the geodesic distance path.
+#[cfg(feature = "geodesic")]
+pub(crate) const WGS84_A: f64 = 6_378_137.0;
+
+/// WGS84 ellipsoid flattening, used by the geodesic distance path.
+#[cfg(feature = "geodesic")]
+pub(crate) const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
 /// Geographic bounds structure
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Bounds {
@@ -44,4 +55,9 @@ pub(crate) const BOUNDS: Bounds = Bounds {
     max_lat: 38.5,
     min_lon: 63.5,
     max_lon: 99.5,
-}; 
\ No newline at end of file
+};
+
+/// Upper bound on the number of cells `cover_bounding_box`/`cover_radius` will
+/// enumerate in one call. A full-`BOUNDS`, full-precision request would otherwise
+/// try to build a `Vec` of up to `2^20 * 2^20` strings and exhaust memory.
+pub(crate) const MAX_COVER_CELLS: u64 = 1_000_000;
\ No newline at end of file
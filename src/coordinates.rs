@@ -1,6 +1,13 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::constants::BOUNDS;
+#[cfg(not(feature = "geodesic"))]
+use crate::constants::EARTH_RADIUS_M;
+#[cfg(feature = "geodesic")]
+use crate::constants::{WGS84_A, WGS84_F};
+use crate::error::{DigipinError, DigipinResult};
+
 /// Represents a geographical coordinate pair.
 ///
 /// This struct holds the latitude and longitude in decimal degrees.
@@ -12,3 +19,273 @@ pub struct Coordinates {
     /// The longitude, in decimal degrees.
     pub longitude: f64,
 }
+
+impl Coordinates {
+    /// Creates validated coordinates from any numeric type convertible to `f64`.
+    ///
+    /// This checks the values against `BOUNDS` before constructing, giving the same
+    /// range errors `get_digipin` would give for the same values. `latitude` and
+    /// `longitude` remain public fields, so a plain struct literal is still possible
+    /// when the caller already knows the values are valid (e.g. re-packaging
+    /// coordinates decoded from a DIGIPIN) — prefer this constructor whenever the
+    /// values come from outside the crate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DigipinError::LatitudeOutOfRange` or `DigipinError::LongitudeOutOfRange`
+    /// if the values fall outside `BOUNDS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use digipin::Coordinates;
+    ///
+    /// let delhi = Coordinates::new(28.6139, 77.2090).unwrap();
+    /// assert!(Coordinates::new(100.0, 77.0).is_err());
+    /// ```
+    pub fn new(latitude: impl Into<f64>, longitude: impl Into<f64>) -> DigipinResult<Self> {
+        Self::checked(latitude.into(), longitude.into())
+    }
+
+    /// Returns a copy with the latitude replaced by `latitude`, re-validated against
+    /// `BOUNDS`.
+    pub fn with_latitude(&self, latitude: impl Into<f64>) -> DigipinResult<Self> {
+        Self::checked(latitude.into(), self.longitude)
+    }
+
+    /// Returns a copy with the longitude replaced by `longitude`, re-validated
+    /// against `BOUNDS`.
+    pub fn with_longitude(&self, longitude: impl Into<f64>) -> DigipinResult<Self> {
+        Self::checked(self.latitude, longitude.into())
+    }
+
+    /// Returns a copy nudged by `d_lat` degrees of latitude and `d_lon` degrees of
+    /// longitude, re-validated against `BOUNDS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use digipin::Coordinates;
+    ///
+    /// let delhi = Coordinates::new(28.6139, 77.2090).unwrap();
+    /// let nudged = delhi.offset(0.01, -0.01).unwrap();
+    /// assert!((nudged.latitude - 28.6239).abs() < 1e-9);
+    /// ```
+    pub fn offset(&self, d_lat: f64, d_lon: f64) -> DigipinResult<Self> {
+        Self::checked(self.latitude + d_lat, self.longitude + d_lon)
+    }
+
+    fn checked(latitude: f64, longitude: f64) -> DigipinResult<Self> {
+        if !(BOUNDS.min_lat..=BOUNDS.max_lat).contains(&latitude) {
+            return Err(DigipinError::LatitudeOutOfRange(latitude));
+        }
+        if !(BOUNDS.min_lon..=BOUNDS.max_lon).contains(&longitude) {
+            return Err(DigipinError::LongitudeOutOfRange(longitude));
+        }
+
+        Ok(Self { latitude, longitude })
+    }
+
+    /// Distance to `other`, in meters.
+    ///
+    /// By default this is the great-circle distance via the haversine formula on a
+    /// sphere of mean Earth radius. Enabling the `geodesic` Cargo feature switches
+    /// to Vincenty's inverse formula on the WGS84 ellipsoid, trading some speed for
+    /// sub-meter accuracy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use digipin::Coordinates;
+    ///
+    /// let delhi = Coordinates::new(28.6139, 77.2090).unwrap();
+    /// let mumbai = Coordinates::new(19.0760, 72.8777).unwrap();
+    /// assert!(delhi.distance_to(&mumbai) > 1_000_000.0);
+    /// ```
+    pub fn distance_to(&self, other: &Coordinates) -> f64 {
+        #[cfg(feature = "geodesic")]
+        {
+            vincenty_distance_m(self, other)
+        }
+        #[cfg(not(feature = "geodesic"))]
+        {
+            haversine_distance_m(self, other)
+        }
+    }
+}
+
+/// Builds coordinates directly from a `(latitude, longitude)` tuple, without the
+/// `BOUNDS` validation `Coordinates::new` performs. Useful when the values are
+/// already known to be valid, e.g. when round-tripping through other geo types.
+impl<T, U> From<(T, U)> for Coordinates
+where
+    T: Into<f64>,
+    U: Into<f64>,
+{
+    fn from((latitude, longitude): (T, U)) -> Self {
+        Self { latitude: latitude.into(), longitude: longitude.into() }
+    }
+}
+
+/// Great-circle distance between two coordinates, in meters, via the haversine
+/// formula: with `phi1`/`phi2` latitudes and `d_phi`/`d_lambda` deltas in radians,
+/// `h = sin²(d_phi/2) + cos(phi1)·cos(phi2)·sin²(d_lambda/2)`, distance `= 2R·asin(√h)`.
+#[cfg(not(feature = "geodesic"))]
+fn haversine_distance_m(a: &Coordinates, b: &Coordinates) -> f64 {
+    let phi1 = a.latitude.to_radians();
+    let phi2 = b.latitude.to_radians();
+    let d_phi = (b.latitude - a.latitude).to_radians();
+    let d_lambda = (b.longitude - a.longitude).to_radians();
+
+    let h = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_M * h.sqrt().asin()
+}
+
+/// Geodesic distance between two coordinates, in meters, via Vincenty's inverse
+/// formula on the WGS84 ellipsoid. Gives up after 200 iterations (returning the
+/// best estimate so far) for the nearly-antipodal inputs where the series is slow
+/// to converge; not a concern for points inside `BOUNDS`.
+#[cfg(feature = "geodesic")]
+fn vincenty_distance_m(a: &Coordinates, b: &Coordinates) -> f64 {
+    let l = (b.longitude - a.longitude).to_radians();
+    let u1 = ((1.0 - WGS84_F) * a.latitude.to_radians().tan()).atan();
+    let u2 = ((1.0 - WGS84_F) * b.latitude.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    let mut cos_sq_alpha = 0.0;
+    let mut sin_sigma = 0.0;
+    let mut cos_sigma = 0.0;
+    let mut sigma = 0.0;
+    let mut cos_2sigma_m = 0.0;
+
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == 0.0 {
+            return 0.0; // coincident points
+        }
+        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        cos_2sigma_m = if cos_sq_alpha == 0.0 {
+            0.0 // equatorial line
+        } else {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        };
+        let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+        let lambda_prev = lambda;
+        lambda = l
+            + (1.0 - c)
+                * WGS84_F
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+        if (lambda - lambda_prev).abs() < 1e-12 {
+            break;
+        }
+    }
+
+    let b_axis = WGS84_A * (1.0 - WGS84_F);
+    let u_sq = cos_sq_alpha * (WGS84_A * WGS84_A - b_axis * b_axis) / (b_axis * b_axis);
+    let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+    let delta_sigma = big_b
+        * sin_sigma
+        * (cos_2sigma_m
+            + big_b / 4.0
+                * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                    - big_b / 6.0
+                        * cos_2sigma_m
+                        * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                        * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+
+    b_axis * big_a * (sigma - delta_sigma)
+}
+
+/// Represents the geographic area covered by a single DIGIPIN cell.
+///
+/// Returned by `get_bounds_from_digipin`, which accepts codes shorter than the full
+/// 10 characters, so the cell named may be anywhere from a few meters to about a
+/// thousand kilometers across.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CellBounds {
+    /// The south-west (minimum latitude, minimum longitude) corner of the cell.
+    pub south_west: Coordinates,
+    /// The north-east (maximum latitude, maximum longitude) corner of the cell.
+    pub north_east: Coordinates,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_to_self_is_zero() {
+        let delhi = Coordinates::new(28.6139, 77.2090).unwrap();
+        assert_eq!(delhi.distance_to(&delhi), 0.0);
+    }
+
+    #[test]
+    fn distance_between_delhi_and_mumbai_is_roughly_right() {
+        let delhi = Coordinates::new(28.6139, 77.2090).unwrap();
+        let mumbai = Coordinates::new(19.0760, 72.8777).unwrap();
+
+        // The great-circle distance is close to 1150 km; allow a generous margin
+        // since this is checking the formula, not pinning an exact figure.
+        let meters = delhi.distance_to(&mumbai);
+        assert!((1_100_000.0..1_200_000.0).contains(&meters), "got {meters}");
+    }
+
+    #[test]
+    fn new_rejects_out_of_bounds_values() {
+        assert!(Coordinates::new(28.6139, 77.2090).is_ok());
+        assert_eq!(Coordinates::new(100.0, 77.0), Err(DigipinError::LatitudeOutOfRange(100.0)));
+        assert_eq!(Coordinates::new(28.0, 200.0), Err(DigipinError::LongitudeOutOfRange(200.0)));
+    }
+
+    #[test]
+    fn with_latitude_and_with_longitude_replace_one_axis() {
+        let delhi = Coordinates::new(28.6139, 77.2090).unwrap();
+
+        let shifted_lat = delhi.with_latitude(29.0).unwrap();
+        assert_eq!(shifted_lat.latitude, 29.0);
+        assert_eq!(shifted_lat.longitude, delhi.longitude);
+
+        let shifted_lon = delhi.with_longitude(78.0).unwrap();
+        assert_eq!(shifted_lon.longitude, 78.0);
+        assert_eq!(shifted_lon.latitude, delhi.latitude);
+
+        assert!(delhi.with_latitude(100.0).is_err());
+    }
+
+    #[test]
+    fn offset_nudges_both_axes_and_re_validates() {
+        let delhi = Coordinates::new(28.6139, 77.2090).unwrap();
+        let nudged = delhi.offset(0.01, -0.01).unwrap();
+
+        assert!((nudged.latitude - 28.6239).abs() < 1e-9);
+        assert!((nudged.longitude - 77.1990).abs() < 1e-9);
+
+        assert!(delhi.offset(100.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn from_tuple_skips_validation() {
+        let coords: Coordinates = (19.0760, 72.8777).into();
+        assert_eq!(coords.latitude, 19.0760);
+        assert_eq!(coords.longitude, 72.8777);
+
+        // Unlike `new`, this does not validate against BOUNDS.
+        let out_of_bounds: Coordinates = (100.0, 200.0).into();
+        assert_eq!(out_of_bounds.latitude, 100.0);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    constants::{BOUNDS, EARTH_RADIUS_M, MAX_COVER_CELLS},
+    coordinates::Coordinates,
+    decode::get_bounds_from_digipin,
+    encode::{encode_indices_prefix, lat_to_index, lon_to_index},
+    error::{DigipinError, DigipinResult},
+};
+
+/// Enumerates every DIGIPIN cell, at the given precision, covering a bounding box.
+///
+/// The box is clamped to `BOUNDS` before covering, so a box extending beyond the
+/// DIGIPIN-covered region only yields the cells of its overlap with `BOUNDS`, and a
+/// box with no overlap yields an empty list.
+///
+/// # Errors
+///
+/// Returns `DigipinError::InvalidPrecision` if `levels` is not in `1..=10`,
+/// `DigipinError::BadBoundingBox` if `sw` is north of, or east of, `ne`, or
+/// `DigipinError::TooManyCells` if the box would enumerate more than
+/// `MAX_COVER_CELLS` cells at the requested precision (e.g. a wide box at high
+/// precision) — narrow the box or reduce `levels` instead.
+///
+/// # Example
+///
+/// ```
+/// use digipin::{cover_bounding_box, Coordinates};
+///
+/// let sw = Coordinates { latitude: 28.60, longitude: 77.20 };
+/// let ne = Coordinates { latitude: 28.62, longitude: 77.22 };
+/// let cells = cover_bounding_box(sw, ne, 6).unwrap();
+/// assert!(!cells.is_empty());
+/// ```
+pub fn cover_bounding_box(
+    sw: Coordinates,
+    ne: Coordinates,
+    levels: u8,
+) -> DigipinResult<Vec<String>> {
+    if !(1..=10).contains(&levels) {
+        return Err(DigipinError::InvalidPrecision(levels));
+    }
+    if sw.latitude > ne.latitude || sw.longitude > ne.longitude {
+        return Err(DigipinError::BadBoundingBox);
+    }
+
+    let min_lat = sw.latitude.max(BOUNDS.min_lat);
+    let max_lat = ne.latitude.min(BOUNDS.max_lat);
+    let min_lon = sw.longitude.max(BOUNDS.min_lon);
+    let max_lon = ne.longitude.min(BOUNDS.max_lon);
+
+    // A box that doesn't overlap BOUNDS at all clamps to an empty (or inverted)
+    // range here; `lat_to_index`/`lon_to_index` would otherwise silently saturate
+    // it to a single edge index and return bogus cells instead of nothing.
+    if min_lat > max_lat || min_lon > max_lon {
+        return Ok(Vec::new());
+    }
+
+    // idx_lat grows as latitude decreases (see encode::lat_to_index), so the
+    // greatest latitude produces the smallest idx_lat and vice versa.
+    let shift = 2 * (10 - levels as u32);
+    let lat_min = lat_to_index(max_lat) >> shift;
+    let lat_max = lat_to_index(min_lat) >> shift;
+    let lon_min = lon_to_index(min_lon) >> shift;
+    let lon_max = lon_to_index(max_lon) >> shift;
+
+    let cell_count = (lat_max - lat_min + 1) as u64 * (lon_max - lon_min + 1) as u64;
+    if cell_count > MAX_COVER_CELLS {
+        return Err(DigipinError::TooManyCells(cell_count));
+    }
+
+    let mut cells = Vec::new();
+    for idx_lat in lat_min..=lat_max {
+        for idx_lon in lon_min..=lon_max {
+            cells.push(encode_indices_prefix(idx_lat << shift, idx_lon << shift, levels));
+        }
+    }
+
+    Ok(cells)
+}
+
+/// Enumerates every DIGIPIN cell, at the given precision, whose center lies within
+/// `meters` of `center`.
+///
+/// This first covers the bounding box enclosing the radius, then filters out cells
+/// whose center falls outside the circle, so it costs one bounding-box cover plus
+/// one bounds lookup per candidate cell.
+///
+/// # Errors
+///
+/// Returns the same errors as `cover_bounding_box` for the derived box, or any
+/// `DigipinError` `get_bounds_from_digipin` would return for a candidate cell (this
+/// should not happen for cells this function itself produced).
+pub fn cover_radius(center: Coordinates, meters: f64, levels: u8) -> DigipinResult<Vec<String>> {
+    let delta_lat = (meters / EARTH_RADIUS_M).to_degrees();
+    let delta_lon = (meters / (EARTH_RADIUS_M * center.latitude.to_radians().cos())).to_degrees();
+
+    let sw = Coordinates {
+        latitude: center.latitude - delta_lat,
+        longitude: center.longitude - delta_lon,
+    };
+    let ne = Coordinates {
+        latitude: center.latitude + delta_lat,
+        longitude: center.longitude + delta_lon,
+    };
+
+    let candidates = cover_bounding_box(sw, ne, levels)?;
+
+    let mut cells = Vec::with_capacity(candidates.len());
+    for code in candidates {
+        let bounds = get_bounds_from_digipin(&code)?;
+        let cell_center = Coordinates {
+            latitude: (bounds.south_west.latitude + bounds.north_east.latitude) / 2.0,
+            longitude: (bounds.south_west.longitude + bounds.north_east.longitude) / 2.0,
+        };
+        if center.distance_to(&cell_center) <= meters {
+            cells.push(code);
+        }
+    }
+
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::get_digipin_with_precision;
+
+    #[test]
+    fn bounding_box_cover_includes_the_corners() {
+        let sw = Coordinates { latitude: 28.60, longitude: 77.20 };
+        let ne = Coordinates { latitude: 28.62, longitude: 77.22 };
+        let cells = cover_bounding_box(sw.clone(), ne.clone(), 6).unwrap();
+
+        assert!(!cells.is_empty());
+        let sw_cell = get_digipin_with_precision(sw.latitude, sw.longitude, 6).unwrap();
+        let ne_cell = get_digipin_with_precision(ne.latitude, ne.longitude, 6).unwrap();
+        assert!(cells.contains(&sw_cell));
+        assert!(cells.contains(&ne_cell));
+
+        // Every returned cell's bounds must actually overlap the requested box.
+        for code in &cells {
+            let bounds = get_bounds_from_digipin(code).unwrap();
+            assert!(bounds.south_west.latitude <= ne.latitude);
+            assert!(bounds.north_east.latitude >= sw.latitude);
+        }
+    }
+
+    #[test]
+    fn bounding_box_outside_bounds_is_empty_not_bogus() {
+        // Entirely north of India: no overlap with BOUNDS at all.
+        let sw = Coordinates { latitude: 40.0, longitude: 77.20 };
+        let ne = Coordinates { latitude: 45.0, longitude: 77.22 };
+        assert_eq!(cover_bounding_box(sw, ne, 6).unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn bounding_box_rejects_an_inverted_box() {
+        let sw = Coordinates { latitude: 28.62, longitude: 77.20 };
+        let ne = Coordinates { latitude: 28.60, longitude: 77.22 };
+        assert_eq!(cover_bounding_box(sw, ne, 6), Err(DigipinError::BadBoundingBox));
+    }
+
+    #[test]
+    fn bounding_box_rejects_bad_precision() {
+        let sw = Coordinates { latitude: 28.60, longitude: 77.20 };
+        let ne = Coordinates { latitude: 28.62, longitude: 77.22 };
+        assert_eq!(cover_bounding_box(sw, ne, 0), Err(DigipinError::InvalidPrecision(0)));
+    }
+
+    #[test]
+    fn bounding_box_rejects_a_request_that_would_enumerate_too_many_cells() {
+        // The full BOUNDS region at full precision is far beyond MAX_COVER_CELLS.
+        let sw = Coordinates { latitude: BOUNDS.min_lat, longitude: BOUNDS.min_lon };
+        let ne = Coordinates { latitude: BOUNDS.max_lat, longitude: BOUNDS.max_lon };
+        match cover_bounding_box(sw, ne, 10) {
+            Err(DigipinError::TooManyCells(count)) => assert!(count > MAX_COVER_CELLS),
+            other => panic!("expected TooManyCells, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn radius_cover_contains_the_center_cell_and_excludes_far_cells() {
+        let center = Coordinates { latitude: 28.6139, longitude: 77.2090 };
+        let cells = cover_radius(center.clone(), 500.0, 8).unwrap();
+
+        assert!(!cells.is_empty());
+        let center_cell = get_digipin_with_precision(center.latitude, center.longitude, 8).unwrap();
+        assert!(cells.contains(&center_cell));
+        for code in &cells {
+            let bounds = get_bounds_from_digipin(code).unwrap();
+            let cell_center = Coordinates {
+                latitude: (bounds.south_west.latitude + bounds.north_east.latitude) / 2.0,
+                longitude: (bounds.south_west.longitude + bounds.north_east.longitude) / 2.0,
+            };
+            assert!(center.distance_to(&cell_center) <= 500.0);
+        }
+    }
+}
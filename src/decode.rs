@@ -1,4 +1,9 @@
-use crate::{constants::{BOUNDS, LOOKUP, POWER, SPAN}, coordinates::Coordinates, error::DigipinResult};
+use crate::{
+    constants::{BOUNDS, LOOKUP, POWER, SPAN},
+    coordinates::{CellBounds, Coordinates},
+    encode::encode_indices,
+    error::{DigipinError, DigipinResult},
+};
 
 /// Decodes a DIGIPIN string into its corresponding geographical coordinates.
 ///
@@ -24,6 +29,28 @@ use crate::{constants::{BOUNDS, LOOKUP, POWER, SPAN}, coordinates::Coordinates,
 /// assert!((coords.longitude - 77.2090).abs() < 1e-4);
 /// ```
 pub fn get_coordinates_from_digipin(digipin: &str) -> DigipinResult<Coordinates> {
+    let (idx_lat, idx_lon) = parse_digipin(digipin)?;
+
+    Ok(indices_to_coordinates(idx_lat, idx_lon))
+}
+
+/// Converts the 20-bit `idx_lat`/`idx_lon` grid indices into the center coordinates
+/// of the cell they name, shared by every function that needs to decode a code down
+/// to indices before looking up coordinates (e.g. the integer form in the `integer`
+/// module).
+pub(crate) fn indices_to_coordinates(idx_lat: u32, idx_lon: u32) -> Coordinates {
+    let frac_lat = (idx_lat as f64 + 0.5) / (POWER as f64);
+    let center_lat = BOUNDS.max_lat - frac_lat * SPAN;
+    let frac_lon = (idx_lon as f64 + 0.5) / (POWER as f64);
+    let center_lon = BOUNDS.min_lon + frac_lon * SPAN;
+
+    Coordinates { latitude: center_lat, longitude: center_lon }
+}
+
+/// Parses a DIGIPIN string (with or without hyphens) into its `idx_lat`/`idx_lon`
+/// grid indices, shared by every function that needs to decode a code before doing
+/// something other than looking up its center coordinates.
+pub(crate) fn parse_digipin(digipin: &str) -> DigipinResult<(u32, u32)> {
     let mut char_iter = digipin.chars().filter(|&c| c != '-');
     let mut idx_lat: u32 = 0;
     let mut idx_lon: u32 = 0;
@@ -37,30 +64,234 @@ pub fn get_coordinates_from_digipin(digipin: &str) -> DigipinResult<Coordinates>
                 idx_lon = (idx_lon << 2) | col as u32;
                 count += 1;
             }
-            None => return Err(crate::error::DigipinError::InvalidLength(count)),
+            None => return Err(DigipinError::InvalidLength(count)),
         }
     }
 
     if char_iter.next().is_some() {
-        return Err(crate::error::DigipinError::InvalidLength(count + 1));
+        return Err(DigipinError::InvalidLength(count + 1));
     }
 
-    let frac_lat = (idx_lat as f64 + 0.5) / (POWER as f64);
-    let center_lat = BOUNDS.max_lat - frac_lat * SPAN;
-    let frac_lon = (idx_lon as f64 + 0.5) / (POWER as f64);
-    let center_lon = BOUNDS.min_lon + frac_lon * SPAN;
+    Ok((idx_lat, idx_lon))
+}
+
+/// Returns the geographic bounds covered by a DIGIPIN or DIGIPIN prefix.
+///
+/// Unlike `get_coordinates_from_digipin`, this accepts codes of 1 to 10 characters
+/// (as produced by `get_digipin_with_precision`), returning the bounds of whatever
+/// cell that prefix names rather than requiring the full precision.
+///
+/// # Errors
+///
+/// Returns `DigipinError::InvalidLength` if `digipin` has no characters or more than
+/// 10, or `DigipinError::InvalidCharacter` if it contains a character outside the
+/// DIGIPIN charset.
+///
+/// # Example
+///
+/// ```
+/// use digipin::get_bounds_from_digipin;
+///
+/// let bounds = get_bounds_from_digipin("39J-438-TJC7").unwrap();
+/// assert!(bounds.south_west.latitude < bounds.north_east.latitude);
+/// assert!(bounds.south_west.longitude < bounds.north_east.longitude);
+/// ```
+pub fn get_bounds_from_digipin(digipin: &str) -> DigipinResult<CellBounds> {
+    let (idx_lat, idx_lon, levels) = parse_digipin_prefix(digipin)?;
+
+    let cell_span = SPAN / (1u64 << (2 * levels)) as f64;
+    let frac_lat = idx_lat as f64 / (POWER as f64);
+    let frac_lon = idx_lon as f64 / (POWER as f64);
+
+    let north = BOUNDS.max_lat - frac_lat * SPAN;
+    let south = north - cell_span;
+    let west = BOUNDS.min_lon + frac_lon * SPAN;
+    let east = west + cell_span;
+
+    Ok(CellBounds {
+        south_west: Coordinates { latitude: south, longitude: west },
+        north_east: Coordinates { latitude: north, longitude: east },
+    })
+}
+
+/// Parses a DIGIPIN prefix of 1 to 10 characters into `idx_lat`/`idx_lon` grid
+/// indices shifted into the top `2 * levels` bits (matching the scale `parse_digipin`
+/// uses for full 10-character codes), along with the number of levels parsed.
+fn parse_digipin_prefix(digipin: &str) -> DigipinResult<(u32, u32, u8)> {
+    let chars: Vec<char> = digipin.chars().filter(|&c| c != '-').collect();
+    let levels = chars.len();
+    if levels == 0 || levels > 10 {
+        return Err(DigipinError::InvalidLength(levels));
+    }
+
+    let mut idx_lat: u32 = 0;
+    let mut idx_lon: u32 = 0;
+    for ch in chars {
+        let (row, col) = find_char_in_grid(ch)?;
+        idx_lat = (idx_lat << 2) | row as u32;
+        idx_lon = (idx_lon << 2) | col as u32;
+    }
 
-    Ok(Coordinates { latitude: center_lat, longitude: center_lon })
+    let shift = 2 * (10 - levels as u32);
+    Ok((idx_lat << shift, idx_lon << shift, levels as u8))
 }
 
 /// Find the position of a character in the DIGIPIN grid
 fn find_char_in_grid(ch: char) -> DigipinResult<(usize, usize)> {
     let idx = ch as u32;
     if idx > 127 {
-        return Err(crate::error::DigipinError::InvalidCharacter(ch));
+        return Err(DigipinError::InvalidCharacter(ch));
     }
     match LOOKUP[idx as usize] {
         Some((row, col)) => Ok((row as usize, col as usize)),
-        None => Err(crate::error::DigipinError::InvalidCharacter(ch)),
+        None => Err(DigipinError::InvalidCharacter(ch)),
+    }
+}
+
+/// One of the eight compass directions relative to a DIGIPIN cell, used to look up
+/// adjacent cells with [`neighbor`] and [`get_neighbors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The `(d_lat, d_lon)` step applied to the cell's grid indices to move one cell
+    /// in this direction. Latitude indices grow southward (see `encode::lat_to_index`),
+    /// so north/south are inverted relative to the index values.
+    fn index_delta(self) -> (i64, i64) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+/// Returns the DIGIPIN of the cell adjacent to `digipin` in the given direction.
+///
+/// # Errors
+///
+/// Returns `DigipinError::NoNeighbor` if `dir` would move outside the DIGIPIN grid
+/// (e.g. asking for the cell north of one already at the top latitude bound).
+///
+/// # Example
+///
+/// ```
+/// use digipin::{neighbor, Direction};
+///
+/// let east = neighbor("39J-438-TJC7", Direction::East).unwrap();
+/// assert_ne!(east, "39J-438-TJC7");
+/// ```
+pub fn neighbor(digipin: &str, dir: Direction) -> DigipinResult<String> {
+    let (idx_lat, idx_lon) = parse_digipin(digipin)?;
+    let (d_lat, d_lon) = dir.index_delta();
+
+    let new_lat = idx_lat as i64 + d_lat;
+    let new_lon = idx_lon as i64 + d_lon;
+
+    if new_lat < 0 || new_lat > (POWER - 1) as i64 || new_lon < 0 || new_lon > (POWER - 1) as i64 {
+        return Err(DigipinError::NoNeighbor);
+    }
+
+    Ok(encode_indices(new_lat as u32, new_lon as u32))
+}
+
+/// Returns the DIGIPINs of all eight cells surrounding `digipin`, ordered North,
+/// North-East, East, South-East, South, South-West, West, North-West.
+///
+/// # Errors
+///
+/// Returns `DigipinError::NoNeighbor` if `digipin` sits on a grid edge and any of
+/// the eight neighbors would fall outside the DIGIPIN grid.
+pub fn get_neighbors(digipin: &str) -> DigipinResult<[String; 8]> {
+    let mut neighbors = Vec::with_capacity(8);
+    for dir in Direction::ALL {
+        neighbors.push(neighbor(digipin, dir)?);
+    }
+
+    Ok(neighbors.try_into().expect("exactly 8 directions"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELHI: &str = "39J-438-TJC7";
+
+    #[test]
+    fn neighbor_steps_one_cell_and_back() {
+        let east = neighbor(DELHI, Direction::East).unwrap();
+        assert_ne!(east, DELHI);
+        assert_eq!(neighbor(&east, Direction::West).unwrap(), DELHI);
+
+        let north = neighbor(DELHI, Direction::North).unwrap();
+        assert_ne!(north, DELHI);
+        assert_eq!(neighbor(&north, Direction::South).unwrap(), DELHI);
+    }
+
+    #[test]
+    fn get_neighbors_returns_eight_distinct_cells() {
+        let neighbors = get_neighbors(DELHI).unwrap();
+        assert_eq!(neighbors.len(), 8);
+        for n in &neighbors {
+            assert_ne!(n, DELHI);
+        }
+        let unique: std::collections::HashSet<&String> = neighbors.iter().collect();
+        assert_eq!(unique.len(), 8);
+    }
+
+    #[test]
+    fn neighbor_off_the_grid_is_rejected() {
+        let corner = encode_indices(0, 0);
+        assert_eq!(neighbor(&corner, Direction::North), Err(DigipinError::NoNeighbor));
+        assert_eq!(neighbor(&corner, Direction::West), Err(DigipinError::NoNeighbor));
+        assert_eq!(get_neighbors(&corner), Err(DigipinError::NoNeighbor));
+    }
+
+    #[test]
+    fn bounds_of_a_prefix_shrink_as_precision_grows() {
+        let coarse = get_bounds_from_digipin("39J-4").unwrap();
+        let fine = get_bounds_from_digipin("39J-438-TJC7").unwrap();
+
+        let coarse_lat_span = coarse.north_east.latitude - coarse.south_west.latitude;
+        let fine_lat_span = fine.north_east.latitude - fine.south_west.latitude;
+        assert!(fine_lat_span < coarse_lat_span);
+
+        // The full-precision cell should still be nested inside the coarse one.
+        assert!(fine.south_west.latitude >= coarse.south_west.latitude);
+        assert!(fine.north_east.latitude <= coarse.north_east.latitude);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn bounds_reject_empty_or_overlong_input() {
+        assert_eq!(get_bounds_from_digipin(""), Err(DigipinError::InvalidLength(0)));
+        assert_eq!(
+            get_bounds_from_digipin("39J-438-TJC7F"),
+            Err(DigipinError::InvalidLength(11))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{decode::get_coordinates_from_digipin, error::DigipinResult};
+
+/// Distance, in meters, between the center coordinates of two DIGIPINs.
+///
+/// Uses `Coordinates::distance_to` under the hood, so it is the haversine
+/// great-circle distance by default, or the WGS84 geodesic distance with the
+/// `geodesic` Cargo feature enabled.
+///
+/// # Errors
+///
+/// Returns a `DigipinError` if `a` or `b` is not a valid DIGIPIN.
+///
+/// # Example
+///
+/// ```
+/// use digipin::{distance_between, get_digipin};
+///
+/// let delhi = get_digipin(28.6139, 77.2090).unwrap();
+/// let mumbai = get_digipin(19.0760, 72.8777).unwrap();
+/// assert!(distance_between(&delhi, &mumbai).unwrap() > 1_000_000.0);
+/// ```
+pub fn distance_between(a: &str, b: &str) -> DigipinResult<f64> {
+    let coords_a = get_coordinates_from_digipin(a)?;
+    let coords_b = get_coordinates_from_digipin(b)?;
+
+    Ok(coords_a.distance_to(&coords_b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_between_matches_coordinates_distance_to() {
+        let delhi = "39J-438-TJC7";
+        let mumbai = crate::encode::get_digipin(19.0760, 72.8777).unwrap();
+
+        let coords_a = get_coordinates_from_digipin(delhi).unwrap();
+        let coords_b = get_coordinates_from_digipin(&mumbai).unwrap();
+
+        assert_eq!(
+            distance_between(delhi, &mumbai).unwrap(),
+            coords_a.distance_to(&coords_b)
+        );
+    }
+
+    #[test]
+    fn distance_between_rejects_an_invalid_digipin() {
+        assert!(distance_between("not-a-pin", "39J-438-TJC7").is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{constants::{BOUNDS, DIGIPIN_GRID, POWER, SPAN}, error::DigipinResult};
+use crate::{
+    constants::{BOUNDS, DIGIPIN_GRID, POWER, SPAN},
+    error::{DigipinError, DigipinResult},
+};
 
 /// Encodes geographical coordinates into a 10-digit alphanumeric DIGIPIN code.
 ///
@@ -24,28 +27,131 @@ use crate::{constants::{BOUNDS, DIGIPIN_GRID, POWER, SPAN}, error::DigipinResult
 /// assert_eq!(digipin, "39J-438-TJC7");
 /// ```
 pub fn get_digipin(latitude: f64, longitude: f64) -> DigipinResult<String> {
+    get_digipin_with_precision(latitude, longitude, 10)
+}
+
+/// Encodes geographical coordinates into a DIGIPIN truncated to `levels` grid
+/// characters (1..=10), naming a coarser cell the fewer levels are requested.
+///
+/// Each level quarters the cell in both axes, so e.g. a 4-level code names a cell
+/// about 15 kilometers across, while the full 10 levels (the default used by
+/// [`get_digipin`]) narrows down to a few meters.
+///
+/// # Errors
+///
+/// Returns `DigipinError::InvalidPrecision` if `levels` is not in `1..=10`, or the
+/// same range errors as `get_digipin` if the coordinates are out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use digipin::get_digipin_with_precision;
+///
+/// let coarse = get_digipin_with_precision(28.6139, 77.2090, 4).unwrap();
+/// assert_eq!(coarse, "39J-4");
+/// ```
+pub fn get_digipin_with_precision(
+    latitude: f64,
+    longitude: f64,
+    levels: u8,
+) -> DigipinResult<String> {
+    if !(1..=10).contains(&levels) {
+        return Err(DigipinError::InvalidPrecision(levels));
+    }
+
+    let (idx_lat, idx_lon) = coordinates_to_indices(latitude, longitude)?;
+
+    Ok(encode_indices_prefix(idx_lat, idx_lon, levels))
+}
+
+/// Validates `latitude`/`longitude` against `BOUNDS` and converts them to the 20-bit
+/// `idx_lat`/`idx_lon` grid indices, shared by every function that needs to encode a
+/// coordinate before doing something other than emitting the full-precision string.
+pub(crate) fn coordinates_to_indices(latitude: f64, longitude: f64) -> DigipinResult<(u32, u32)> {
     if !(BOUNDS.min_lat..=BOUNDS.max_lat).contains(&latitude) {
-        return Err(crate::error::DigipinError::LatitudeOutOfRange(latitude));
+        return Err(DigipinError::LatitudeOutOfRange(latitude));
     }
     if !(BOUNDS.min_lon..=BOUNDS.max_lon).contains(&longitude) {
-        return Err(crate::error::DigipinError::LongitudeOutOfRange(longitude));
+        return Err(DigipinError::LongitudeOutOfRange(longitude));
     }
 
+    Ok((lat_to_index(latitude), lon_to_index(longitude)))
+}
+
+/// Converts a latitude, assumed already within `BOUNDS`, to its 20-bit grid index.
+/// `idx_lat` grows as latitude decreases from `BOUNDS.max_lat`.
+pub(crate) fn lat_to_index(latitude: f64) -> u32 {
     let frac_lat = (BOUNDS.max_lat - latitude) / SPAN;
-    let idx_lat = ((frac_lat * (POWER as f64)) as u32).min(POWER - 1);
+    ((frac_lat * (POWER as f64)) as u32).min(POWER - 1)
+}
+
+/// Converts a longitude, assumed already within `BOUNDS`, to its 20-bit grid index.
+/// `idx_lon` grows as longitude increases from `BOUNDS.min_lon`.
+pub(crate) fn lon_to_index(longitude: f64) -> u32 {
     let frac_lon = (longitude - BOUNDS.min_lon) / SPAN;
-    let idx_lon = ((frac_lon * (POWER as f64)) as u32).min(POWER - 1);
+    ((frac_lon * (POWER as f64)) as u32).min(POWER - 1)
+}
+
+/// Renders the 20-bit `idx_lat`/`idx_lon` grid indices as a hyphenated DIGIPIN string.
+///
+/// Used by code that needs to re-emit a full-precision code from adjusted indices
+/// rather than from raw coordinates (e.g. the neighbor lookups in the decode module
+/// and the integer form in the `integer` module).
+pub(crate) fn encode_indices(idx_lat: u32, idx_lon: u32) -> String {
+    encode_indices_prefix(idx_lat, idx_lon, 10)
+}
 
+/// Like `encode_indices`, but emits only the first `levels` (1..=10) grid characters,
+/// inserting the hyphen separators only where those positions fall.
+pub(crate) fn encode_indices_prefix(idx_lat: u32, idx_lon: u32, levels: u8) -> String {
     let mut digipin = String::with_capacity(12);
-    for level in 0..10 {
+    for level in 0..levels as u32 {
         let shift = 18 - 2 * level;
         let row = ((idx_lat >> shift) & 3) as usize;
         let col = ((idx_lon >> shift) & 3) as usize;
         digipin.push(DIGIPIN_GRID[row][col]);
-        if level == 2 || level == 5 {
+        if (level == 2 || level == 5) && level + 1 < levels as u32 {
             digipin.push('-');
         }
     }
 
-    Ok(digipin)
-} 
\ No newline at end of file
+    digipin
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precision_truncates_to_the_requested_levels() {
+        let full = get_digipin_with_precision(28.6139, 77.2090, 10).unwrap();
+        assert_eq!(full, "39J-438-TJC7");
+
+        let coarse = get_digipin_with_precision(28.6139, 77.2090, 4).unwrap();
+        assert_eq!(coarse, "39J-4");
+    }
+
+    #[test]
+    fn precision_never_leaves_a_trailing_hyphen() {
+        for levels in 1..=10u8 {
+            let digipin = get_digipin_with_precision(28.6139, 77.2090, levels).unwrap();
+            assert!(
+                !digipin.ends_with('-'),
+                "levels={levels} produced a trailing hyphen: {digipin:?}"
+            );
+            assert_eq!(digipin.chars().filter(|&c| c != '-').count(), levels as usize);
+        }
+    }
+
+    #[test]
+    fn precision_out_of_range_is_rejected() {
+        assert_eq!(
+            get_digipin_with_precision(28.6139, 77.2090, 0),
+            Err(DigipinError::InvalidPrecision(0))
+        );
+        assert_eq!(
+            get_digipin_with_precision(28.6139, 77.2090, 11),
+            Err(DigipinError::InvalidPrecision(11))
+        );
+    }
+}
\ No newline at end of file
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::constants::MAX_COVER_CELLS;
+
 /// Represents the possible errors that can occur during DIGIPIN encoding or decoding.
 #[derive(Debug, Clone, PartialEq)]
 pub enum DigipinError {
@@ -13,6 +15,18 @@ pub enum DigipinError {
     /// Returned when the DIGIPIN string contains a character that is not part of the
     /// valid charset.
     InvalidCharacter(char),
+    /// Returned when a requested neighbor would fall outside the DIGIPIN grid (e.g.
+    /// the cell north of one already at the top latitude bound).
+    NoNeighbor,
+    /// Returned when a requested precision (number of grid levels) is outside the
+    /// valid range of `1..=10`.
+    InvalidPrecision(u8),
+    /// Returned when a bounding box's south-west corner is north of, or east of,
+    /// its north-east corner.
+    BadBoundingBox,
+    /// Returned when a region-covering request (a bounding box or radius at a
+    /// given precision) would enumerate more than `MAX_COVER_CELLS` cells.
+    TooManyCells(u64),
 }
 
 impl fmt::Display for DigipinError {
@@ -30,6 +44,20 @@ impl fmt::Display for DigipinError {
             DigipinError::InvalidCharacter(ch) => {
                 write!(f, "Invalid character '{}' in DIGIPIN", ch)
             }
+            DigipinError::NoNeighbor => {
+                write!(f, "No neighbor in that direction: grid boundary reached")
+            }
+            DigipinError::InvalidPrecision(levels) => {
+                write!(f, "Invalid DIGIPIN precision: {} (expected 1 to 10)", levels)
+            }
+            DigipinError::BadBoundingBox => {
+                write!(f, "Invalid bounding box: south-west corner must be south-west of north-east corner")
+            }
+            DigipinError::TooManyCells(count) => write!(
+                f,
+                "Region covers {} cells, exceeding the limit of {} (use a coarser precision or a smaller region)",
+                count, MAX_COVER_CELLS
+            ),
         }
     }
 }
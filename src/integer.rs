@@ -0,0 +1,118 @@
+use crate::{
+    coordinates::Coordinates,
+    decode::{indices_to_coordinates, parse_digipin},
+    encode::{coordinates_to_indices, encode_indices},
+    error::DigipinResult,
+};
+
+/// Encodes geographical coordinates directly into the compact u64 integer form of a
+/// DIGIPIN.
+///
+/// The `idx_lat`/`idx_lon` grid indices are interleaved into the low 40 bits so that
+/// numerically sorting the resulting integers preserves the DIGIPIN cell hierarchy,
+/// making them usable as keys in a B-tree/LSM index without string parsing.
+///
+/// # Errors
+///
+/// Returns the same range errors as `get_digipin` if the coordinates are out of
+/// bounds.
+///
+/// # Example
+///
+/// ```
+/// use digipin::{get_digipin, get_digipin_int, int_to_digipin};
+///
+/// let code = get_digipin_int(28.6139, 77.2090).unwrap();
+/// assert_eq!(int_to_digipin(code), get_digipin(28.6139, 77.2090).unwrap());
+/// ```
+pub fn get_digipin_int(latitude: f64, longitude: f64) -> DigipinResult<u64> {
+    let (idx_lat, idx_lon) = coordinates_to_indices(latitude, longitude)?;
+
+    Ok(interleave(idx_lat, idx_lon))
+}
+
+/// Decodes the compact u64 integer form of a DIGIPIN back into the center
+/// coordinates of the cell it names.
+pub fn digipin_int_to_coordinates(code: u64) -> Coordinates {
+    let (idx_lat, idx_lon) = deinterleave(code);
+
+    indices_to_coordinates(idx_lat, idx_lon)
+}
+
+/// Converts a DIGIPIN string into its compact u64 integer form.
+///
+/// # Errors
+///
+/// Returns a `DigipinError` if `digipin` is not a valid DIGIPIN.
+pub fn digipin_to_int(digipin: &str) -> DigipinResult<u64> {
+    let (idx_lat, idx_lon) = parse_digipin(digipin)?;
+
+    Ok(interleave(idx_lat, idx_lon))
+}
+
+/// Converts the compact u64 integer form of a DIGIPIN back into its hyphenated
+/// string form.
+pub fn int_to_digipin(code: u64) -> String {
+    let (idx_lat, idx_lon) = deinterleave(code);
+
+    encode_indices(idx_lat, idx_lon)
+}
+
+/// Interleaves the 20-bit `idx_lat`/`idx_lon` grid indices into a 40-bit Morton code:
+/// a latitude bit then a longitude bit at each level, from the most significant bit
+/// down, matching the level order used by the string form.
+fn interleave(idx_lat: u32, idx_lon: u32) -> u64 {
+    let mut code: u64 = 0;
+    for bit in (0..20).rev() {
+        let lat_bit = ((idx_lat >> bit) & 1) as u64;
+        let lon_bit = ((idx_lon >> bit) & 1) as u64;
+        code = (code << 2) | (lat_bit << 1) | lon_bit;
+    }
+    code
+}
+
+/// The inverse of `interleave`.
+fn deinterleave(code: u64) -> (u32, u32) {
+    let mut idx_lat: u32 = 0;
+    let mut idx_lon: u32 = 0;
+    for level in (0..20).rev() {
+        let shift = level * 2;
+        let lat_bit = ((code >> (shift + 1)) & 1) as u32;
+        let lon_bit = ((code >> shift) & 1) as u32;
+        idx_lat = (idx_lat << 1) | lat_bit;
+        idx_lon = (idx_lon << 1) | lon_bit;
+    }
+    (idx_lat, idx_lon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_form_round_trips_through_the_string_form() {
+        let code = get_digipin_int(28.6139, 77.2090).unwrap();
+        assert_eq!(int_to_digipin(code), "39J-438-TJC7");
+        assert_eq!(digipin_to_int("39J-438-TJC7").unwrap(), code);
+    }
+
+    #[test]
+    fn interleave_and_deinterleave_are_inverses() {
+        for &(idx_lat, idx_lon) in &[(0, 0), (1, 0), (0, 1), (0xABCDE, 0x12345), (0xFFFFF, 0xFFFFF)] {
+            assert_eq!(deinterleave(interleave(idx_lat, idx_lon)), (idx_lat, idx_lon));
+        }
+    }
+
+    #[test]
+    fn int_form_preserves_the_sort_order_of_nearby_cells() {
+        // idx_lat grows as latitude decreases, so the northern point sorts first.
+        let north = get_digipin_int(28.70, 77.2090).unwrap();
+        let south = get_digipin_int(28.60, 77.2090).unwrap();
+        assert!(north < south);
+    }
+
+    #[test]
+    fn digipin_to_int_rejects_an_invalid_digipin() {
+        assert!(digipin_to_int("not-a-pin").is_err());
+    }
+}
@@ -3,14 +3,22 @@ use serde::{Deserialize, Serialize};
 
 mod constants;
 mod coordinates;
+mod cover;
 mod decode;
+mod distance;
 mod encode;
 mod error;
+mod integer;
 
-pub use coordinates::Coordinates;
+pub use coordinates::{CellBounds, Coordinates};
 pub use error::{DigipinError, DigipinResult};
-pub use encode::get_digipin;
-pub use decode::get_coordinates_from_digipin;
+pub use encode::{get_digipin, get_digipin_with_precision};
+pub use decode::{
+    get_bounds_from_digipin, get_coordinates_from_digipin, get_neighbors, neighbor, Direction,
+};
+pub use integer::{digipin_int_to_coordinates, digipin_to_int, get_digipin_int, int_to_digipin};
+pub use cover::{cover_bounding_box, cover_radius};
+pub use distance::distance_between;
 
 #[cfg(test)]
 mod tests {